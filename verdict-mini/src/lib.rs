@@ -3,7 +3,7 @@ use pinocchio::{
     entrypoint,
     msg,
     program_error::ProgramError,
-    pubkey::find_program_address,
+    pubkey::{create_program_address, find_program_address},
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
@@ -12,6 +12,19 @@ entrypoint!(process_instruction);
 
 const VERDICT_LEN: usize = 73;
 
+/// Canonical verdict PDA seed prefix. Must match `VERDICT_SEED_PREFIX` in the
+/// Anchor `verdictswarm-onchain` program so both use the same seeds,
+/// `[b"verdict", scan_hash]` — this program no longer uses its own `b"v"`
+/// prefix. The PDA is derived under this program's own id, so it is not the
+/// same address as the Anchor program's PDA for the same `scan_hash`.
+///
+/// Note this key is `scan_hash` alone: this minimal account format never
+/// carried a token address or chain, so there is nothing on-chain to bind
+/// `scan_hash` back to what it was scanned for. The caller is trusted to
+/// have derived `scan_hash` as a stable, collision-resistant commitment to
+/// `(token_address, chain)` off-chain before calling this program.
+const VERDICT_SEED_PREFIX: &[u8] = b"verdict";
+
 fn process_instruction(
     program_id: &[u8; 32],
     accounts: &[AccountInfo],
@@ -28,16 +41,33 @@ fn process_instruction(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    // Exactly 32 bytes by construction (Solana's max single-seed length),
+    // so it needs no further splitting or truncation before being used as
+    // a seed.
     let scan_hash = &data[0..32];
 
-    let (pda, bump) = find_program_address(&[b"v", scan_hash], program_id);
+    let (pda, bump) = find_program_address(&[VERDICT_SEED_PREFIX, scan_hash], program_id);
     if pda != *verdict.key() {
         return Err(ProgramError::InvalidAccountData);
     }
 
+    // Defense in depth: `find_program_address` already returns the canonical
+    // bump, but we re-derive with `create_program_address` and the bump
+    // that is stored below explicitly so a non-canonical bump can never slip through,
+    // matching the safety guarantee the Anchor program gets for free from
+    // its `bump` seeds constraint.
+    let bump_slice = [bump];
+    let rederived = create_program_address(
+        &[VERDICT_SEED_PREFIX, scan_hash, &bump_slice],
+        program_id,
+    )
+    .map_err(|_| ProgramError::InvalidSeeds)?;
+    if rederived != pda {
+        return Err(ProgramError::InvalidSeeds);
+    }
+
     let rent = Rent::get()?;
     let lamports = rent.minimum_balance(VERDICT_LEN);
-    let bump_slice = [bump];
 
     pinocchio_system::instructions::CreateAccount {
         from: authority,
@@ -47,7 +77,7 @@ fn process_instruction(
         owner: program_id,
     }
     .invoke_signed(&[pinocchio::instruction::Signer::from(&[
-        pinocchio::instruction::Seed::from(b"v".as_ref()),
+        pinocchio::instruction::Seed::from(VERDICT_SEED_PREFIX),
         pinocchio::instruction::Seed::from(scan_hash),
         pinocchio::instruction::Seed::from(bump_slice.as_ref()),
     ])])?;
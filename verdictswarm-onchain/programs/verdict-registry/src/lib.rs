@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+declare_id!("Reg1stryVswrmNM8XXXXXXXXXXXXXXXXXXXXXXXXXXX");
+
+/// The only program ID allowed to drive `record_latest` through its
+/// `cpi_authority` PDA. Hardcoding the caller keeps this registry
+/// single-writer without needing a runtime allowlist.
+pub mod verdictswarm_onchain_id {
+    anchor_lang::declare_id!("3i6GVUgshmbymqrsvxWQMX98yKzqLxNRUHEhtwRBZ35p");
+}
+
+pub const CPI_AUTHORITY_SEED: &[u8] = b"cpi-authority";
+pub const REGISTRY_SEED: &[u8] = b"registry";
+
+#[program]
+pub mod verdict_registry {
+    use super::*;
+
+    /// Bumps the per-authority counter and overwrites the latest-scan slot.
+    /// Only callable via CPI from `verdictswarm-onchain`'s own signing PDA —
+    /// a direct, non-CPI call cannot produce that signer and is rejected.
+    pub fn record_latest(ctx: Context<RecordLatest>, scan_hash: [u8; 32], timestamp: i64) -> Result<()> {
+        let registry = &mut ctx.accounts.registry;
+
+        registry.authority = ctx.accounts.authority.key();
+        registry.total_verdicts = registry
+            .total_verdicts
+            .checked_add(1)
+            .ok_or(RegistryError::CounterOverflow)?;
+        registry.latest_scan_hash = scan_hash;
+        registry.latest_timestamp = timestamp;
+        registry.bump = ctx.bumps.registry;
+
+        msg!(
+            "VerdictRegistry: authority {} now has {} verdicts, latest at {}",
+            registry.authority,
+            registry.total_verdicts,
+            registry.latest_timestamp,
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RecordLatest<'info> {
+    /// The signing PDA owned by `verdictswarm-onchain`. Anchor checks both
+    /// that this account is a signer (only true when invoked via
+    /// `invoke_signed` with the matching seeds) and that it was derived
+    /// from that specific program, so no other caller can satisfy it.
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED],
+        bump,
+        seeds::program = verdictswarm_onchain_id::ID,
+    )]
+    pub cpi_authority: Signer<'info>,
+
+    /// CHECK: only used as a seed for the per-authority registry PDA.
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AuthorityRegistry::INIT_SPACE,
+        seeds = [REGISTRY_SEED, authority.key().as_ref()],
+        bump,
+    )]
+    pub registry: Account<'info, AuthorityRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AuthorityRegistry {
+    pub authority: Pubkey,        // 32
+    pub total_verdicts: u64,      // 8
+    pub latest_scan_hash: [u8; 32], // 32
+    pub latest_timestamp: i64,    // 8
+    pub bump: u8,                 // 1
+}
+
+#[error_code]
+pub enum RegistryError {
+    #[msg("total_verdicts counter overflowed")]
+    CounterOverflow,
+}
+
+// Open follow-up: the rejection of a direct, non-CPI call to `record_latest`
+// (Anchor's generated account validation for `seeds::program =
+// verdictswarm_onchain_id::ID` on `cpi_authority`) is untested. This repo
+// has no `tests/` directory or workspace test runner, and unit tests alone
+// can't submit a transaction to observe the runtime reject one — that needs
+// a BanksClient/litesvm-style harness, which is out of scope for this
+// series. Nothing below stands in for that coverage.
@@ -0,0 +1,166 @@
+//! Fixed-layout encode/decode helpers for the cross-chain verdict payload
+//! published through the Wormhole core bridge. Every destination-chain
+//! guard contract parses this exact byte order, so fields are written and
+//! read big-endian and never reordered.
+
+use anchor_lang::prelude::*;
+
+use crate::VerdictError;
+
+pub const VERDICT_PAYLOAD_ID: u8 = 1;
+
+pub const VERDICT_PAYLOAD_LEN: usize = 1 // payload id
+    + 32 // scan_hash
+    + 2  // score (big-endian)
+    + 1  // grade code
+    + 2  // chain id (big-endian)
+    + 64; // token address, right-padded
+
+/// The wire representation of a `Verdict` as published to other chains.
+pub struct VerdictPayload {
+    pub scan_hash: [u8; 32],
+    pub score: u16,
+    pub grade_code: u8,
+    pub chain_id: u16,
+    pub token_address: [u8; 64],
+}
+
+impl VerdictPayload {
+    pub fn new(scan_hash: [u8; 32], score: u16, grade_code: u8, chain_id: u16, token_address: &str) -> Result<Self> {
+        require!(
+            token_address.len() <= 64,
+            VerdictError::TokenAddressTooLong
+        );
+
+        let mut padded = [0u8; 64];
+        padded[..token_address.len()].copy_from_slice(token_address.as_bytes());
+
+        Ok(Self {
+            scan_hash,
+            score,
+            grade_code,
+            chain_id,
+            token_address: padded,
+        })
+    }
+
+    pub fn to_bytes(&self) -> [u8; VERDICT_PAYLOAD_LEN] {
+        let mut buf = [0u8; VERDICT_PAYLOAD_LEN];
+        let mut offset = 0;
+
+        buf[offset] = VERDICT_PAYLOAD_ID;
+        offset += 1;
+
+        buf[offset..offset + 32].copy_from_slice(&self.scan_hash);
+        offset += 32;
+
+        buf[offset..offset + 2].copy_from_slice(&self.score.to_be_bytes());
+        offset += 2;
+
+        buf[offset] = self.grade_code;
+        offset += 1;
+
+        buf[offset..offset + 2].copy_from_slice(&self.chain_id.to_be_bytes());
+        offset += 2;
+
+        buf[offset..offset + 64].copy_from_slice(&self.token_address);
+
+        buf
+    }
+
+    pub fn from_bytes(buf: &[u8]) -> Result<Self> {
+        require!(
+            buf.len() == VERDICT_PAYLOAD_LEN,
+            VerdictError::InvalidPayloadLength
+        );
+        require!(
+            buf[0] == VERDICT_PAYLOAD_ID,
+            VerdictError::InvalidPayloadId
+        );
+
+        let mut offset = 1;
+
+        let mut scan_hash = [0u8; 32];
+        scan_hash.copy_from_slice(&buf[offset..offset + 32]);
+        offset += 32;
+
+        let score = u16::from_be_bytes(buf[offset..offset + 2].try_into().unwrap());
+        offset += 2;
+
+        let grade_code = buf[offset];
+        offset += 1;
+
+        let chain_id = u16::from_be_bytes(buf[offset..offset + 2].try_into().unwrap());
+        offset += 2;
+
+        let mut token_address = [0u8; 64];
+        token_address.copy_from_slice(&buf[offset..offset + 64]);
+
+        Ok(Self {
+            scan_hash,
+            score,
+            grade_code,
+            chain_id,
+            token_address,
+        })
+    }
+}
+
+/// Maps a VerdictSwarm chain name to the Wormhole chain ID used on the wire,
+/// since the payload travels over the Wormhole core bridge. Returns `0` for
+/// any name this function doesn't recognize; callers that publish the
+/// result must reject `0` rather than let an unrecognized chain go out
+/// indistinguishable from a legitimate "unset" id.
+pub fn wormhole_chain_id(chain: &str) -> u16 {
+    match chain.to_ascii_lowercase().as_str() {
+        "solana" => 1,
+        "ethereum" | "eth" => 2,
+        "bsc" | "binance" => 4,
+        "polygon" | "matic" => 5,
+        "avalanche" | "avax" => 6,
+        "arbitrum" => 23,
+        "optimism" => 24,
+        "base" => 30,
+        "sui" => 21,
+        "aptos" => 22,
+        _ => 0,
+    }
+}
+
+/// The payload carries a single grade byte rather than the full string;
+/// this takes the first ASCII byte of the grade (e.g. `A+` -> `b'A'`).
+pub fn grade_code(grade: &str) -> u8 {
+    grade.as_bytes().first().copied().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_bytes() {
+        let payload = VerdictPayload::new([7u8; 32], 950, b'A', 2, "0xDEADBEEF").unwrap();
+        let bytes = payload.to_bytes();
+        assert_eq!(bytes.len(), VERDICT_PAYLOAD_LEN);
+
+        let decoded = VerdictPayload::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.scan_hash, [7u8; 32]);
+        assert_eq!(decoded.score, 950);
+        assert_eq!(decoded.grade_code, b'A');
+        assert_eq!(decoded.chain_id, 2);
+        assert_eq!(&decoded.token_address[..10], b"0xDEADBEEF");
+        assert!(decoded.token_address[10..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert!(VerdictPayload::from_bytes(&[0u8; 10]).is_err());
+    }
+
+    #[test]
+    fn maps_known_chains_to_wormhole_ids() {
+        assert_eq!(wormhole_chain_id("solana"), 1);
+        assert_eq!(wormhole_chain_id("ethereum"), 2);
+        assert_eq!(wormhole_chain_id("unknown-chain"), 0);
+    }
+}
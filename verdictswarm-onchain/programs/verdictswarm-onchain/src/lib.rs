@@ -1,7 +1,40 @@
 use anchor_lang::prelude::*;
+use anchor_lang::Space;
+use wormhole_anchor_sdk::wormhole;
+
+mod byte_utils;
+
+use byte_utils::{grade_code, wormhole_chain_id, VerdictPayload};
+use verdict_registry::{
+    cpi::accounts::RecordLatest, program::VerdictRegistry, AuthorityRegistry, CPI_AUTHORITY_SEED,
+};
 
 declare_id!("3i6GVUgshmbymqrsvxWQMX98yKzqLxNRUHEhtwRBZ35p");
 
+pub const TOKEN_ADDRESS_MAX_LEN: usize = 64;
+pub const CHAIN_MAX_LEN: usize = 16;
+pub const GRADE_MAX_LEN: usize = 4;
+pub const TIER_MAX_LEN: usize = 16;
+
+pub const VERDICT_MESSAGE_SEED_PREFIX: &[u8] = b"sent";
+
+/// Canonical verdict PDA seed prefix, shared with the `verdict-mini`
+/// Pinocchio program so both use the same seeds, `[b"verdict", scan_hash]`.
+/// Each program derives the PDA under its own program id, so the same
+/// `scan_hash` still yields a different address in each program.
+///
+/// Keying on `scan_hash` alone (rather than `(token_address, chain,
+/// scan_hash)`) means nothing on-chain ties a verdict PDA back to the
+/// token/chain it's claimed for — that binding is an off-chain assumption:
+/// `scan_hash` must already be a commitment computed from `(token_address,
+/// chain)` (plus whatever scan inputs) by the caller, so two different
+/// `(token_address, chain)` pairs can never collide on the same PDA. This
+/// program does not and cannot verify that commitment itself; it trusts
+/// the caller-supplied `scan_hash` as-is. `verdict-mini`'s minimal account
+/// format never carried `token_address`/`chain` at all, which is why the
+/// shared seed can't include them.
+pub const VERDICT_SEED_PREFIX: &[u8] = b"verdict";
+
 #[program]
 pub mod verdictswarm_onchain {
     use super::*;
@@ -16,11 +49,16 @@ pub mod verdictswarm_onchain {
         tier: String,
         scan_hash: [u8; 32],
     ) -> Result<()> {
-        require!(token_address.len() <= 64, VerdictError::TokenAddressTooLong);
-        require!(chain.len() <= 16, VerdictError::ChainTooLong);
+        require!(
+            token_address.len() <= TOKEN_ADDRESS_MAX_LEN,
+            VerdictError::TokenAddressTooLong
+        );
+        require!(chain.len() <= CHAIN_MAX_LEN, VerdictError::ChainTooLong);
         require!(score <= 1000, VerdictError::ScoreOutOfRange);
-        require!(grade.len() <= 4, VerdictError::GradeTooLong);
-        require!(tier.len() <= 16, VerdictError::TierTooLong);
+        require!(grade.len() <= GRADE_MAX_LEN, VerdictError::GradeTooLong);
+        require!(tier.len() <= TIER_MAX_LEN, VerdictError::TierTooLong);
+
+        let agents_hash = verify_agent_quorum(ctx.remaining_accounts, agent_count)?;
 
         let verdict = &mut ctx.accounts.verdict;
         let clock = Clock::get()?;
@@ -35,6 +73,26 @@ pub mod verdictswarm_onchain {
         verdict.timestamp = clock.unix_timestamp;
         verdict.scan_hash = scan_hash;
         verdict.bump = ctx.bumps.verdict;
+        verdict.revision = 0;
+        verdict.sequence = 0;
+        verdict.agents_hash = agents_hash;
+
+        let cpi_authority_bump = ctx.bumps.cpi_authority;
+        verdict_registry::cpi::record_latest(
+            CpiContext::new_with_signer(
+                ctx.accounts.registry_program.to_account_info(),
+                RecordLatest {
+                    cpi_authority: ctx.accounts.cpi_authority.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    registry: ctx.accounts.registry.to_account_info(),
+                    payer: ctx.accounts.authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[&[CPI_AUTHORITY_SEED, &[cpi_authority_bump]]],
+            ),
+            verdict.scan_hash,
+            verdict.timestamp,
+        )?;
 
         msg!(
             "VerdictSwarm: Stored verdict for {} on {} — score {}/1000, grade {}",
@@ -46,6 +104,185 @@ pub mod verdictswarm_onchain {
 
         Ok(())
     }
+
+    pub fn update_verdict(
+        ctx: Context<UpdateVerdict>,
+        _scan_hash: [u8; 32],
+        score: u16,
+        grade: String,
+        agent_count: u8,
+        tier: String,
+    ) -> Result<()> {
+        require!(score <= 1000, VerdictError::ScoreOutOfRange);
+        require!(grade.len() <= GRADE_MAX_LEN, VerdictError::GradeTooLong);
+        require!(tier.len() <= TIER_MAX_LEN, VerdictError::TierTooLong);
+
+        // `agent_count` is a co-signed, cryptographically-committed quantity
+        // (see `agents_hash`); a re-scan must re-clear the same quorum bar,
+        // or the stored count and the commitment to who signed would drift
+        // apart.
+        let agents_hash = verify_agent_quorum(ctx.remaining_accounts, agent_count)?;
+
+        let verdict = &mut ctx.accounts.verdict;
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            verdict.authority,
+            VerdictError::Unauthorized
+        );
+
+        let clock = Clock::get()?;
+
+        verdict.score = score;
+        verdict.grade = grade;
+        verdict.agent_count = agent_count;
+        verdict.agents_hash = agents_hash;
+        verdict.tier = tier;
+        verdict.timestamp = clock.unix_timestamp;
+        verdict.revision = verdict
+            .revision
+            .checked_add(1)
+            .ok_or(VerdictError::RevisionOverflow)?;
+
+        // Keep the registry's "latest" slot in lockstep with re-scans too,
+        // the same way `store_verdict` does for the initial scan — otherwise
+        // `latest_scan_hash`/`latest_timestamp` would silently go stale the
+        // moment a verdict is updated.
+        let cpi_authority_bump = ctx.bumps.cpi_authority;
+        verdict_registry::cpi::record_latest(
+            CpiContext::new_with_signer(
+                ctx.accounts.registry_program.to_account_info(),
+                RecordLatest {
+                    cpi_authority: ctx.accounts.cpi_authority.to_account_info(),
+                    authority: ctx.accounts.authority.to_account_info(),
+                    registry: ctx.accounts.registry.to_account_info(),
+                    payer: ctx.accounts.authority.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[&[CPI_AUTHORITY_SEED, &[cpi_authority_bump]]],
+            ),
+            verdict.scan_hash,
+            verdict.timestamp,
+        )?;
+
+        msg!(
+            "VerdictSwarm: Updated verdict for {} on {} — revision {}, score {}/1000, grade {}",
+            verdict.token_address,
+            verdict.chain,
+            verdict.revision,
+            verdict.score,
+            verdict.grade,
+        );
+
+        Ok(())
+    }
+
+    pub fn publish_verdict(ctx: Context<PublishVerdict>, _scan_hash: [u8; 32]) -> Result<()> {
+        let verdict = &mut ctx.accounts.verdict;
+
+        require_keys_eq!(
+            ctx.accounts.authority.key(),
+            verdict.authority,
+            VerdictError::Unauthorized
+        );
+
+        let chain_id = wormhole_chain_id(&verdict.chain);
+        require!(chain_id != 0, VerdictError::UnknownChain);
+
+        let payload = VerdictPayload::new(
+            verdict.scan_hash,
+            verdict.score,
+            grade_code(&verdict.grade),
+            chain_id,
+            &verdict.token_address,
+        )?;
+
+        let bridge_fee = ctx.accounts.wormhole_bridge.config.fee;
+        if bridge_fee > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    },
+                ),
+                bridge_fee,
+            )?;
+        }
+
+        // The core bridge gives the posted message the tracker's current
+        // value and then increments the tracker, so read it before posting.
+        let sequence = ctx.accounts.wormhole_sequence.sequence;
+
+        wormhole::post_message(
+            CpiContext::new_with_signer(
+                ctx.accounts.wormhole_program.to_account_info(),
+                wormhole::PostMessage {
+                    config: ctx.accounts.wormhole_bridge.to_account_info(),
+                    message: ctx.accounts.wormhole_message.to_account_info(),
+                    emitter: ctx.accounts.wormhole_emitter.to_account_info(),
+                    sequence: ctx.accounts.wormhole_sequence.to_account_info(),
+                    payer: ctx.accounts.authority.to_account_info(),
+                    fee_collector: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    rent: ctx.accounts.rent.to_account_info(),
+                },
+                &[
+                    &[
+                        VERDICT_MESSAGE_SEED_PREFIX,
+                        &verdict.scan_hash,
+                        &verdict.revision.to_le_bytes(),
+                        &[ctx.bumps.wormhole_message],
+                    ],
+                    &[wormhole::SEED_PREFIX_EMITTER, &[ctx.bumps.wormhole_emitter]],
+                ],
+            ),
+            0,
+            payload.to_bytes().to_vec(),
+            wormhole::Finality::Confirmed,
+        )?;
+
+        verdict.sequence = sequence;
+
+        msg!(
+            "VerdictSwarm: Published verdict for {} on {} — sequence {}",
+            verdict.token_address,
+            verdict.chain,
+            verdict.sequence,
+        );
+
+        Ok(())
+    }
+}
+
+/// Verifies that `agent_count` distinct agent pubkeys actually signed this
+/// transaction (passed via `remaining_accounts`) and commits the quorum to a
+/// single hash so the on-chain record attests to exactly who co-signed.
+fn verify_agent_quorum(remaining_accounts: &[AccountInfo], agent_count: u8) -> Result<[u8; 32]> {
+    require!(
+        remaining_accounts.len() == agent_count as usize,
+        VerdictError::AgentCountMismatch
+    );
+
+    let mut agent_keys: Vec<Pubkey> = Vec::with_capacity(remaining_accounts.len());
+    for agent in remaining_accounts {
+        require!(agent.is_signer, VerdictError::QuorumNotMet);
+        require!(
+            !agent_keys.contains(agent.key),
+            VerdictError::DuplicateAgentSigner
+        );
+        agent_keys.push(*agent.key);
+    }
+
+    agent_keys.sort();
+
+    let mut preimage = Vec::with_capacity(agent_keys.len() * 32);
+    for key in &agent_keys {
+        preimage.extend_from_slice(key.as_ref());
+    }
+
+    Ok(anchor_lang::solana_program::hash::hash(&preimage).to_bytes())
 }
 
 #[derive(Accounts)]
@@ -57,48 +294,152 @@ pub struct StoreVerdict<'info> {
     #[account(
         init,
         payer = authority,
-        space = Verdict::space(&token_address, &chain, &grade, &tier),
+        space = 8 + Verdict::INIT_SPACE,
+        seeds = [VERDICT_SEED_PREFIX, &scan_hash],
+        bump,
+    )]
+    pub verdict: Account<'info, Verdict>,
+
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: PDA used solely as this program's signer for the registry CPI.
+    pub cpi_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: validated and (de)serialized by `verdict_registry::record_latest` itself.
+    pub registry: UncheckedAccount<'info>,
+
+    pub registry_program: Program<'info, VerdictRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(scan_hash: [u8; 32])]
+pub struct UpdateVerdict<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    // `Verdict` is fixed-size (see chunk0-1: `INIT_SPACE` always reserves
+    // each string's `max_len`), so the account never needs to grow or
+    // shrink on update — no `realloc` here.
+    #[account(
+        mut,
+        seeds = [VERDICT_SEED_PREFIX, &scan_hash],
+        bump = verdict.bump,
+    )]
+    pub verdict: Account<'info, Verdict>,
+
+    #[account(
+        seeds = [CPI_AUTHORITY_SEED],
+        bump,
+    )]
+    /// CHECK: PDA used solely as this program's signer for the registry CPI.
+    pub cpi_authority: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    /// CHECK: validated and (de)serialized by `verdict_registry::record_latest` itself.
+    pub registry: UncheckedAccount<'info>,
+
+    pub registry_program: Program<'info, VerdictRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(scan_hash: [u8; 32])]
+pub struct PublishVerdict<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VERDICT_SEED_PREFIX, &scan_hash],
+        bump = verdict.bump,
+    )]
+    pub verdict: Account<'info, Verdict>,
+
+    #[account(
+        seeds = [wormhole::SEED_PREFIX_EMITTER],
+        bump,
+    )]
+    /// CHECK: Wormhole emitter PDA for this program; only ever used as a signer seed.
+    pub wormhole_emitter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [wormhole::BridgeData::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program.key(),
+    )]
+    pub wormhole_bridge: Account<'info, wormhole::BridgeData>,
+
+    #[account(
+        mut,
+        seeds = [wormhole::FeeCollector::SEED_PREFIX],
+        bump,
+        seeds::program = wormhole_program.key(),
+    )]
+    /// CHECK: Wormhole core bridge fee collector; the bridge program validates this.
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
         seeds = [
-            b"verdict",
-            token_address.as_bytes(),
-            chain.as_bytes(),
+            wormhole::SequenceTracker::SEED_PREFIX,
+            wormhole_emitter.key().as_ref(),
+        ],
+        bump,
+        seeds::program = wormhole_program.key(),
+    )]
+    pub wormhole_sequence: Account<'info, wormhole::SequenceTracker>,
+
+    // `post_message` writes this account exactly once — the core bridge
+    // rejects a second write to the same message PDA. The seed includes
+    // `verdict.revision`, which starts at 0 and only moves forward via
+    // `update_verdict`, so each re-scan gets its own message account; a
+    // second publish of the same revision reuses a message account the
+    // bridge already owns and is rejected.
+    #[account(
+        mut,
+        seeds = [
+            VERDICT_MESSAGE_SEED_PREFIX,
             &scan_hash,
+            &verdict.revision.to_le_bytes(),
         ],
         bump,
     )]
-    pub verdict: Account<'info, Verdict>,
+    /// CHECK: Wormhole message account; the core bridge writes the VAA payload into it.
+    pub wormhole_message: UncheckedAccount<'info>,
 
+    pub wormhole_program: Program<'info, wormhole::program::Wormhole>,
     pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Verdict {
-    pub authority: Pubkey,       // 32
-    pub token_address: String,   // 4 + len
-    pub chain: String,           // 4 + len
-    pub score: u16,              // 2
-    pub grade: String,           // 4 + len
-    pub agent_count: u8,         // 1
-    pub tier: String,            // 4 + len
-    pub timestamp: i64,          // 8
-    pub scan_hash: [u8; 32],     // 32
-    pub bump: u8,                // 1
-}
-
-impl Verdict {
-    pub fn space(token_address: &str, chain: &str, grade: &str, tier: &str) -> usize {
-        8  // discriminator
-        + 32 // authority
-        + 4 + token_address.len() // token_address
-        + 4 + chain.len() // chain
-        + 2  // score
-        + 4 + grade.len() // grade
-        + 1  // agent_count
-        + 4 + tier.len() // tier
-        + 8  // timestamp
-        + 32 // scan_hash
-        + 1  // bump
-    }
+    pub authority: Pubkey, // 32
+    #[max_len(TOKEN_ADDRESS_MAX_LEN)]
+    pub token_address: String, // 4 + len, always reserved at max
+    #[max_len(CHAIN_MAX_LEN)]
+    pub chain: String, // 4 + len, always reserved at max
+    pub score: u16, // 2
+    #[max_len(GRADE_MAX_LEN)]
+    pub grade: String, // 4 + len, always reserved at max
+    pub agent_count: u8, // 1
+    #[max_len(TIER_MAX_LEN)]
+    pub tier: String, // 4 + len, always reserved at max
+    pub timestamp: i64,     // 8
+    pub scan_hash: [u8; 32], // 32
+    pub bump: u8,           // 1
+    pub revision: u32,      // 4
+    pub sequence: u64,      // 8, Wormhole sequence number of the last published attestation
+    pub agents_hash: [u8; 32], // 32, sha256 of the sorted co-signing agent pubkeys
 }
 
 #[error_code]
@@ -113,4 +454,106 @@ pub enum VerdictError {
     GradeTooLong,
     #[msg("Tier exceeds 16 characters")]
     TierTooLong,
+    #[msg("Only the verdict's original authority may update it")]
+    Unauthorized,
+    #[msg("Revision counter overflowed")]
+    RevisionOverflow,
+    #[msg("Verdict attestation payload has an unexpected length")]
+    InvalidPayloadLength,
+    #[msg("Verdict attestation payload id does not match")]
+    InvalidPayloadId,
+    #[msg("Number of co-signing agent accounts does not match agent_count")]
+    AgentCountMismatch,
+    #[msg("Not enough agents actually signed to meet the declared quorum")]
+    QuorumNotMet,
+    #[msg("The same agent pubkey was supplied more than once")]
+    DuplicateAgentSigner,
+    #[msg("Verdict's chain is not a recognized Wormhole chain")]
+    UnknownChain,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn init_space_matches_maxed_out_verdict() {
+        let maxed = Verdict {
+            authority: Pubkey::default(),
+            token_address: "a".repeat(TOKEN_ADDRESS_MAX_LEN),
+            chain: "a".repeat(CHAIN_MAX_LEN),
+            score: 1000,
+            grade: "a".repeat(GRADE_MAX_LEN),
+            agent_count: u8::MAX,
+            tier: "a".repeat(TIER_MAX_LEN),
+            timestamp: i64::MAX,
+            scan_hash: [0u8; 32],
+            bump: 255,
+            revision: u32::MAX,
+            sequence: u64::MAX,
+            agents_hash: [0u8; 32],
+        };
+
+        let serialized = AnchorSerialize::try_to_vec(&maxed).unwrap();
+        assert_eq!(serialized.len(), Verdict::INIT_SPACE);
+    }
+
+    fn signer_account_info<'a>(key: &'a Pubkey, lamports: &'a mut u64, data: &'a mut [u8]) -> AccountInfo<'a> {
+        AccountInfo::new(
+            key,
+            true,  // is_signer
+            false, // is_writable
+            lamports,
+            data,
+            &anchor_lang::system_program::ID,
+            false,
+            0,
+        )
+    }
+
+    #[test]
+    fn quorum_succeeds_with_exact_distinct_signers() {
+        let key_a = Pubkey::new_unique();
+        let key_b = Pubkey::new_unique();
+        let key_c = Pubkey::new_unique();
+        let (mut lamports_a, mut lamports_b, mut lamports_c) = (0u64, 0u64, 0u64);
+        let (mut data_a, mut data_b, mut data_c) = (Vec::<u8>::new(), Vec::<u8>::new(), Vec::<u8>::new());
+
+        let infos = vec![
+            signer_account_info(&key_a, &mut lamports_a, &mut data_a),
+            signer_account_info(&key_b, &mut lamports_b, &mut data_b),
+            signer_account_info(&key_c, &mut lamports_c, &mut data_c),
+        ];
+
+        assert!(verify_agent_quorum(&infos, 3).is_ok());
+    }
+
+    #[test]
+    fn quorum_rejects_count_mismatch() {
+        let key_a = Pubkey::new_unique();
+        let key_b = Pubkey::new_unique();
+        let (mut lamports_a, mut lamports_b) = (0u64, 0u64);
+        let (mut data_a, mut data_b) = (Vec::<u8>::new(), Vec::<u8>::new());
+
+        let infos = vec![
+            signer_account_info(&key_a, &mut lamports_a, &mut data_a),
+            signer_account_info(&key_b, &mut lamports_b, &mut data_b),
+        ];
+
+        assert!(verify_agent_quorum(&infos, 3).is_err());
+    }
+
+    #[test]
+    fn quorum_rejects_duplicate_signers() {
+        let key = Pubkey::new_unique();
+        let (mut lamports_a, mut lamports_b) = (0u64, 0u64);
+        let (mut data_a, mut data_b) = (Vec::<u8>::new(), Vec::<u8>::new());
+
+        let infos = vec![
+            signer_account_info(&key, &mut lamports_a, &mut data_a),
+            signer_account_info(&key, &mut lamports_b, &mut data_b),
+        ];
+
+        assert!(verify_agent_quorum(&infos, 2).is_err());
+    }
 }